@@ -5,6 +5,7 @@ pub enum LogFormat {
     #[default]
     Human,
     GitHub,
+    Json,
 }
 
 impl LogFormat {
@@ -13,12 +14,13 @@ impl LogFormat {
         match self {
             LogFormat::Human => "human",
             LogFormat::GitHub => "github",
+            LogFormat::Json => "json",
         }
     }
 
     #[must_use]
     pub const fn variants() -> &'static [&'static str] {
-        &["human", "github"]
+        &["human", "github", "json"]
     }
 }
 
@@ -34,6 +36,7 @@ impl std::str::FromStr for LogFormat {
         match s.to_ascii_lowercase().as_str() {
             "human" => Ok(LogFormat::Human),
             "github" | "gh" => Ok(LogFormat::GitHub),
+            "json" => Ok(LogFormat::Json),
             other => Err(format!(
                 "invalid diagnostics format '{other}'. expected one of: {}",
                 Self::variants().join(", ")
@@ -42,14 +45,602 @@ impl std::str::FromStr for LogFormat {
     }
 }
 
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Controls whether `Human`-format output is styled with ANSI escapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Color when stderr is a terminal and `NO_COLOR` is unset.
+    #[default]
+    Auto,
+    /// Always color, even when redirected to a file or pipe.
+    Always,
+    /// Never color.
+    Never,
+}
+
+impl ColorChoice {
+    fn resolve(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none() && console::Term::stderr().is_term()
+            }
+        }
+    }
+}
+
+/// Minimum severity a record must have to be emitted, ordered from most to
+/// least restrictive so `record_level <= configured_filter` decides whether
+/// a record passes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LevelFilter {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl std::str::FromStr for LevelFilter {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" => Ok(LevelFilter::Off),
+            "error" => Ok(LevelFilter::Error),
+            "warn" => Ok(LevelFilter::Warn),
+            "info" => Ok(LevelFilter::Info),
+            "debug" => Ok(LevelFilter::Debug),
+            "trace" => Ok(LevelFilter::Trace),
+            other => Err(format!(
+                "invalid level filter '{other}'. expected one of: off, error, warn, info, debug, trace"
+            )),
+        }
+    }
+}
+
+/// A parsed severity directive, e.g. `"warn,ingest::csv=debug,ingest::xml=trace"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelDirectives {
+    global: LevelFilter,
+    modules: Vec<(String, LevelFilter)>,
+}
+
+impl LevelDirectives {
+    /// Parses a comma-separated directive: bare levels set the global
+    /// filter, `module=level` entries set a per-module override.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut global = LevelFilter::Trace;
+        let mut modules = Vec::new();
+        for entry in spec.split(',') {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+            match entry.split_once('=') {
+                Some((module, level)) => {
+                    modules.push((module.trim().to_string(), level.trim().parse()?));
+                }
+                None => global = entry.parse()?,
+            }
+        }
+        Ok(Self { global, modules })
+    }
+
+    fn level_for(&self, module: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .find(|(m, _)| m == module)
+            .map_or(self.global, |(_, level)| *level)
+    }
+}
+
+impl Default for LevelDirectives {
+    fn default() -> Self {
+        Self {
+            global: LevelFilter::Trace,
+            modules: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Location {
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub col: Option<u32>,
+    pub end_line: Option<u32>,
+    pub end_col: Option<u32>,
+}
+
+impl Location {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    #[must_use]
+    pub fn line(mut self, line: u32) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    #[must_use]
+    pub fn col(mut self, col: u32) -> Self {
+        self.col = Some(col);
+        self
+    }
+
+    #[must_use]
+    pub fn end_line(mut self, end_line: u32) -> Self {
+        self.end_line = Some(end_line);
+        self
+    }
+
+    #[must_use]
+    pub fn end_col(mut self, end_col: u32) -> Self {
+        self.end_col = Some(end_col);
+        self
+    }
+
+    fn github_annotation(&self, kind: &str, message: &str) -> String {
+        let mut props = Vec::new();
+        if let Some(f) = &self.file {
+            props.push(format!("file={f}"));
+        }
+        if let Some(l) = self.line {
+            props.push(format!("line={l}"));
+        }
+        if let Some(l) = self.end_line {
+            props.push(format!("endLine={l}"));
+        }
+        if let Some(c) = self.col {
+            props.push(format!("col={c}"));
+        }
+        if let Some(c) = self.end_col {
+            props.push(format!("endColumn={c}"));
+        }
+        if props.is_empty() {
+            format!("::{kind}::{message}")
+        } else {
+            format!("::{kind} {}::{message}", props.join(","))
+        }
+    }
+
+    fn human_prefix(&self) -> String {
+        let Some(file) = &self.file else {
+            return String::new();
+        };
+        let mut prefix = file.clone();
+        if let Some(line) = self.line {
+            prefix.push(':');
+            prefix.push_str(&line.to_string());
+            if let Some(col) = self.col {
+                prefix.push(':');
+                prefix.push_str(&col.to_string());
+            }
+        }
+        prefix
+    }
+
+    fn to_json(&self) -> String {
+        let mut fields = Vec::new();
+        if let Some(f) = &self.file {
+            fields.push(format!("\"file\":\"{}\"", json_escape(f)));
+        }
+        if let Some(l) = self.line {
+            fields.push(format!("\"line\":{l}"));
+        }
+        if let Some(c) = self.col {
+            fields.push(format!("\"col\":{c}"));
+        }
+        if let Some(l) = self.end_line {
+            fields.push(format!("\"endLine\":{l}"));
+        }
+        if let Some(c) = self.end_col {
+            fields.push(format!("\"endColumn\":{c}"));
+        }
+        format!("{{{}}}", fields.join(","))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    pub file: String,
+    pub byte_range: std::ops::Range<usize>,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<String>,
+    pub message: String,
+    pub labels: Vec<Label>,
+}
+
+impl Diagnostic {
+    #[must_use]
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: message.into(),
+            labels: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_label(
+        mut self,
+        file: impl Into<String>,
+        byte_range: std::ops::Range<usize>,
+        label: impl Into<String>,
+    ) -> Self {
+        self.labels.push(Label {
+            file: file.into(),
+            byte_range,
+            label: label.into(),
+        });
+        self
+    }
+
+    fn to_json(&self) -> String {
+        let mut out = format!(
+            "{{\"severity\":\"{}\",\"message\":\"{}\"",
+            self.severity.as_str(),
+            json_escape(&self.message)
+        );
+        if let Some(code) = &self.code {
+            out.push_str(&format!(",\"code\":\"{}\"", json_escape(code)));
+        }
+        out.push_str(",\"labels\":[");
+        for (i, label) in self.labels.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str(&format!(
+                "{{\"file\":\"{}\",\"start\":{},\"end\":{},\"label\":\"{}\"}}",
+                json_escape(&label.file),
+                label.byte_range.start,
+                label.byte_range.end,
+                json_escape(&label.label)
+            ));
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+fn floor_char_boundary(source: &str, mut offset: usize) -> usize {
+    while offset > 0 && !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+fn locate(source: &str, byte_offset: usize) -> (usize, usize, std::ops::Range<usize>) {
+    let byte_offset = floor_char_boundary(source, byte_offset.min(source.len()));
+    let mut line = 1;
+    let mut line_start = 0;
+    for (i, b) in source.bytes().enumerate() {
+        if i >= byte_offset {
+            break;
+        }
+        if b == b'\n' {
+            line += 1;
+            line_start = i + 1;
+        }
+    }
+    let line_end = source[line_start..]
+        .find('\n')
+        .map_or(source.len(), |n| line_start + n);
+    let col = source[line_start..byte_offset].chars().count() + 1;
+    (line, col, line_start..line_end)
+}
+
+fn render_label(source: &str, label: &Label, caret_style: &dyn Fn(&str) -> String) -> String {
+    let (line_no, col, line_range) = locate(source, label.byte_range.start);
+    let line_text = &source[line_range.clone()];
+    let gutter = line_no.to_string();
+    let pad = " ".repeat(gutter.len());
+    let end = floor_char_boundary(
+        source,
+        label.byte_range.end.max(label.byte_range.start).min(line_range.end),
+    );
+    let end_col = source[line_range.start..end].chars().count() + 1;
+    let caret_len = end_col.saturating_sub(col).max(1);
+    let caret_indent = " ".repeat(col.saturating_sub(1));
+    let carets = caret_style(&"^".repeat(caret_len));
+    format!(
+        "{pad} |\n{gutter} | {line_text}\n{pad} | {caret_indent}{carets} {}",
+        label.label
+    )
+}
+
+fn diagnostic_github_location(diagnostic: &Diagnostic, source: &str) -> Location {
+    diagnostic
+        .labels
+        .first()
+        .map(|label| {
+            let (line, col, _) = locate(source, label.byte_range.start);
+            Location::new()
+                .file(label.file.clone())
+                .line(line as u32)
+                .col(col as u32)
+        })
+        .unwrap_or_default()
+}
+
+fn json_line(level: &str, message: &str, context: &[String], location: Option<&Location>) -> String {
+    let mut out = format!(
+        "{{\"level\":\"{}\",\"message\":\"{}\"",
+        json_escape(level),
+        json_escape(message)
+    );
+    if !context.is_empty() {
+        out.push_str(",\"context\":[");
+        for (i, c) in context.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push('"');
+            out.push_str(&json_escape(c));
+            out.push('"');
+        }
+        out.push(']');
+    }
+    if let Some(location) = location {
+        out.push_str(",\"location\":");
+        out.push_str(&location.to_json());
+    }
+    out.push_str(&format!(",\"ts\":\"{}\"}}", now_rfc3339()));
+    out
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogSegment {
+    Literal(String),
+    Level,
+    Message,
+    Timestamp,
+    Context { sep: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogTemplate {
+    segments: Vec<LogSegment>,
+}
+
+impl LogTemplate {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars();
+        while let Some(c) = chars.next() {
+            if c != '{' {
+                literal.push(c);
+                continue;
+            }
+            if !literal.is_empty() {
+                segments.push(LogSegment::Literal(std::mem::take(&mut literal)));
+            }
+            let mut placeholder = String::new();
+            let mut closed = false;
+            for c2 in chars.by_ref() {
+                if c2 == '}' {
+                    closed = true;
+                    break;
+                }
+                placeholder.push(c2);
+            }
+            if !closed {
+                return Err(format!(
+                    "unterminated placeholder '{{{placeholder}' in log template"
+                ));
+            }
+            segments.push(Self::parse_placeholder(&placeholder)?);
+        }
+        if !literal.is_empty() {
+            segments.push(LogSegment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    fn parse_placeholder(raw: &str) -> Result<LogSegment, String> {
+        let mut parts = raw.splitn(2, ':');
+        let name = parts.next().unwrap_or("").trim();
+        let modifiers = parts.next();
+        match name {
+            "level" => Ok(LogSegment::Level),
+            "message" => Ok(LogSegment::Message),
+            "timestamp" => Ok(LogSegment::Timestamp),
+            "context" => Ok(LogSegment::Context {
+                sep: Self::parse_context_sep(modifiers)?,
+            }),
+            other => Err(format!(
+                "unknown placeholder '{{{other}}}' in log template; expected one of: \
+                 {{level}}, {{message}}, {{context}}, {{timestamp}}"
+            )),
+        }
+    }
+
+    fn parse_context_sep(modifiers: Option<&str>) -> Result<String, String> {
+        let Some(modifiers) = modifiers else {
+            return Ok(" > ".to_string());
+        };
+        let (key, value) = modifiers
+            .split_once('=')
+            .ok_or_else(|| format!("malformed modifier '{modifiers}' on {{context}} placeholder"))?;
+        if key.trim() != "sep" {
+            return Err(format!(
+                "unknown modifier '{}' on {{context}} placeholder; expected 'sep'",
+                key.trim()
+            ));
+        }
+        Ok(value.to_string())
+    }
+
+    fn render(&self, level: &str, message: &str, context: &[String]) -> String {
+        let mut out = String::new();
+        for segment in &self.segments {
+            match segment {
+                LogSegment::Literal(lit) => out.push_str(lit),
+                LogSegment::Level => out.push_str(level),
+                LogSegment::Message => out.push_str(message),
+                LogSegment::Timestamp => out.push_str(&now_rfc3339()),
+                LogSegment::Context { sep } => out.push_str(&context.join(sep)),
+            }
+        }
+        out
+    }
+}
+
+impl std::str::FromStr for LogTemplate {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+fn now_rfc3339() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+    let (h, m, s) = (rem / 3600, (rem % 3600) / 60, rem % 60);
+    let (y, mo, d) = civil_from_days(days as i64);
+    format!("{y:04}-{mo:02}-{d:02}T{h:02}:{m:02}:{s:02}Z")
+}
+
 pub struct Logger {
     format: LogFormat,
+    template: Option<LogTemplate>,
+    filter: LevelDirectives,
+    color: bool,
 }
 
+/// Builds a [`Logger`] with an optional template, filter, and color choice
+/// that compose independently instead of clobbering one another.
+pub struct LoggerBuilder {
+    format: LogFormat,
+    template: Option<LogTemplate>,
+    filter: LevelDirectives,
+    color: ColorChoice,
+}
+
+impl LoggerBuilder {
+    fn new(format: LogFormat) -> Self {
+        Self {
+            format,
+            template: None,
+            filter: LevelDirectives::default(),
+            color: ColorChoice::default(),
+        }
+    }
+
+    /// Renders `Human`-format output through `template` instead of the built-in layout.
+    #[must_use]
+    pub fn template(mut self, template: LogTemplate) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Drops records below the threshold for their module tag before any formatting work.
+    #[must_use]
+    pub fn filter(mut self, filter: LevelDirectives) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Resolves `color` against the environment and applies it to every styled call.
+    #[must_use]
+    pub fn color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> Logger {
+        Logger {
+            format: self.format,
+            template: self.template,
+            filter: self.filter,
+            color: self.color.resolve(),
+        }
+    }
+}
+
+type StyleFn = Box<dyn Fn(&str) -> String>;
+
 impl Logger {
     #[must_use]
     pub fn new(format: LogFormat) -> Self {
-        Self { format }
+        Self::builder(format).build()
+    }
+
+    /// Starts a [`LoggerBuilder`] so template, filter, and color can be set together.
+    #[must_use]
+    pub fn builder(format: LogFormat) -> LoggerBuilder {
+        LoggerBuilder::new(format)
     }
 
     #[must_use]
@@ -57,51 +648,101 @@ impl Logger {
         self.format == LogFormat::Human
     }
 
-    pub fn info(&self, message: impl std::fmt::Display) {
+    /// Applies the resolved color choice to `value`, overriding `console`'s own auto-detection.
+    fn style<D: std::fmt::Display>(&self, value: D) -> console::StyledObject<D> {
+        style(value).force_styling(self.color)
+    }
+
+    fn enabled(&self, level: LevelFilter, module: &str) -> bool {
+        level <= self.filter.level_for(module)
+    }
+
+    pub fn info(&self, module: &str, message: impl std::fmt::Display) {
+        if !self.enabled(LevelFilter::Info, module) {
+            return;
+        }
         match self.format {
-            LogFormat::Human => eprintln!("{message}"),
+            LogFormat::Human => match &self.template {
+                Some(template) => {
+                    eprintln!("{}", template.render("info", &message.to_string(), &[]));
+                }
+                None => eprintln!("{message}"),
+            },
             LogFormat::GitHub => println!("{message}"),
+            LogFormat::Json => println!("{}", json_line("info", &message.to_string(), &[], None)),
         }
     }
 
-    pub fn warn(&self, message: impl std::fmt::Display) {
+    pub fn warn(&self, module: &str, message: impl std::fmt::Display) {
+        if !self.enabled(LevelFilter::Warn, module) {
+            return;
+        }
         match self.format {
-            LogFormat::Human => {
-                eprintln!("{} {}", style("warning:").yellow().bold(), message);
-            }
+            LogFormat::Human => match &self.template {
+                Some(template) => {
+                    eprintln!("{}", template.render("warning", &message.to_string(), &[]));
+                }
+                None => eprintln!("{} {}", self.style("warning:").yellow().bold(), message),
+            },
             LogFormat::GitHub => println!("::warning::{message}"),
+            LogFormat::Json => println!("{}", json_line("warning", &message.to_string(), &[], None)),
         }
     }
 
-    pub fn error(&self, message: impl std::fmt::Display) {
+    pub fn error(&self, module: &str, message: impl std::fmt::Display) {
+        if !self.enabled(LevelFilter::Error, module) {
+            return;
+        }
         match self.format {
-            LogFormat::Human => eprintln!("{} {}", style("error:").red().bold(), message),
+            LogFormat::Human => match &self.template {
+                Some(template) => {
+                    eprintln!("{}", template.render("error", &message.to_string(), &[]));
+                }
+                None => eprintln!("{} {}", self.style("error:").red().bold(), message),
+            },
             LogFormat::GitHub => println!("::error::{message}"),
+            LogFormat::Json => println!("{}", json_line("error", &message.to_string(), &[], None)),
         }
     }
 
-    pub fn error_with_context(&self, context: &[String], message: impl std::fmt::Display) {
+    pub fn error_with_context(
+        &self,
+        module: &str,
+        context: &[String],
+        message: impl std::fmt::Display,
+    ) {
+        if !self.enabled(LevelFilter::Error, module) {
+            return;
+        }
         match self.format {
-            LogFormat::Human => {
-                let context_str = if context.is_empty() {
-                    String::new()
-                } else {
-                    format!(
-                        "[{}]",
-                        context
-                            .iter()
-                            .map(|c| style(c).cyan().to_string())
-                            .collect::<Vec<_>>()
-                            .join(" > ")
-                    )
-                };
-                eprintln!(
-                    "{}{} {}",
-                    style("error:").red().bold(),
-                    context_str,
-                    message
-                );
-            }
+            LogFormat::Human => match &self.template {
+                Some(template) => {
+                    eprintln!(
+                        "{}",
+                        template.render("error", &message.to_string(), context)
+                    );
+                }
+                None => {
+                    let context_str = if context.is_empty() {
+                        String::new()
+                    } else {
+                        format!(
+                            "[{}]",
+                            context
+                                .iter()
+                                .map(|c| self.style(c).cyan().to_string())
+                                .collect::<Vec<_>>()
+                                .join(" > ")
+                        )
+                    };
+                    eprintln!(
+                        "{}{} {}",
+                        self.style("error:").red().bold(),
+                        context_str,
+                        message
+                    );
+                }
+            },
             LogFormat::GitHub => {
                 let msg = if context.is_empty() {
                     message.to_string()
@@ -110,6 +751,116 @@ impl Logger {
                 };
                 println!("::error::{msg}");
             }
+            LogFormat::Json => println!("{}", json_line("error", &message.to_string(), context, None)),
+        }
+    }
+
+    pub fn warn_with_location(
+        &self,
+        module: &str,
+        location: &Location,
+        message: impl std::fmt::Display,
+    ) {
+        if !self.enabled(LevelFilter::Warn, module) {
+            return;
+        }
+        match self.format {
+            LogFormat::Human => {
+                let prefix = location.human_prefix();
+                let body = match &self.template {
+                    Some(template) => template.render("warning", &message.to_string(), &[]),
+                    None => format!("{} {}", self.style("warning:").yellow().bold(), message),
+                };
+                if prefix.is_empty() {
+                    eprintln!("{body}");
+                } else {
+                    eprintln!("{prefix}: {body}");
+                }
+            }
+            LogFormat::GitHub => println!("{}", location.github_annotation("warning", &message.to_string())),
+            LogFormat::Json => {
+                println!(
+                    "{}",
+                    json_line("warning", &message.to_string(), &[], Some(location))
+                );
+            }
+        }
+    }
+
+    pub fn error_with_location(
+        &self,
+        module: &str,
+        location: &Location,
+        message: impl std::fmt::Display,
+    ) {
+        if !self.enabled(LevelFilter::Error, module) {
+            return;
+        }
+        match self.format {
+            LogFormat::Human => {
+                let prefix = location.human_prefix();
+                let body = match &self.template {
+                    Some(template) => template.render("error", &message.to_string(), &[]),
+                    None => format!("{} {}", self.style("error:").red().bold(), message),
+                };
+                if prefix.is_empty() {
+                    eprintln!("{body}");
+                } else {
+                    eprintln!("{prefix}: {body}");
+                }
+            }
+            LogFormat::GitHub => println!("{}", location.github_annotation("error", &message.to_string())),
+            LogFormat::Json => {
+                println!(
+                    "{}",
+                    json_line("error", &message.to_string(), &[], Some(location))
+                );
+            }
+        }
+    }
+
+    pub fn emit_diagnostic(&self, diagnostic: &Diagnostic, source: &str) {
+        match self.format {
+            LogFormat::Human => {
+                let color = self.color;
+                let (prefix, style_fn): (String, StyleFn) = match diagnostic.severity {
+                    Severity::Error => (
+                        diagnostic
+                            .code
+                            .as_ref()
+                            .map_or_else(|| "error".to_string(), |code| format!("error[{code}]")),
+                        Box::new(move |s: &str| {
+                            style(s).red().bold().force_styling(color).to_string()
+                        }),
+                    ),
+                    Severity::Warning => (
+                        diagnostic.code.as_ref().map_or_else(
+                            || "warning".to_string(),
+                            |code| format!("warning[{code}]"),
+                        ),
+                        Box::new(move |s: &str| {
+                            style(s).yellow().bold().force_styling(color).to_string()
+                        }),
+                    ),
+                };
+                match &self.template {
+                    Some(template) => {
+                        eprintln!("{}", template.render(&prefix, &diagnostic.message, &[]));
+                    }
+                    None => eprintln!("{}: {}", style_fn(&prefix), diagnostic.message),
+                }
+                for label in &diagnostic.labels {
+                    eprintln!("{}", render_label(source, label, style_fn.as_ref()));
+                }
+            }
+            LogFormat::GitHub => {
+                let location = diagnostic_github_location(diagnostic, source);
+                println!(
+                    "{}",
+                    location.github_annotation(diagnostic.severity.as_str(), &diagnostic.message)
+                );
+            }
+            LogFormat::Json => println!("{}", diagnostic.to_json()),
         }
     }
 }
@@ -122,27 +873,85 @@ pub fn init(format: LogFormat) {
     let _ = LOGGER.set(Logger::new(format));
 }
 
+pub fn init_with_template(format: LogFormat, template: LogTemplate) {
+    let _ = LOGGER.set(Logger::builder(format).template(template).build());
+}
+
+/// Like [`init`], but parses `directive` into a [`LevelDirectives`].
+///
+/// # Errors
+/// Returns an error if `directive` contains an invalid level.
+pub fn init_with_filter(format: LogFormat, directive: &str) -> Result<(), String> {
+    let filter = LevelDirectives::parse(directive)?;
+    let _ = LOGGER.set(Logger::builder(format).filter(filter).build());
+    Ok(())
+}
+
+/// Like [`init`], but resolves `color` against the environment once instead
+/// of relying on `console`'s own auto-detection.
+pub fn init_with_color(format: LogFormat, color: ColorChoice) {
+    let _ = LOGGER.set(Logger::builder(format).color(color).build());
+}
+
+/// Like [`init`], but combines a template, filter, and color choice in one call.
+pub fn init_with(
+    format: LogFormat,
+    template: Option<LogTemplate>,
+    filter: LevelDirectives,
+    color: ColorChoice,
+) {
+    let mut builder = Logger::builder(format).filter(filter).color(color);
+    if let Some(template) = template {
+        builder = builder.template(template);
+    }
+    let _ = LOGGER.set(builder.build());
+}
+
 fn logger() -> &'static Logger {
     LOGGER.get_or_init(|| Logger::new(LogFormat::default()))
 }
 
 pub mod log {
-    use super::logger;
+    use super::{logger, Diagnostic, Location};
 
-    pub fn info(message: impl std::fmt::Display) {
-        logger().info(message);
+    pub fn info(module: &str, message: impl std::fmt::Display) {
+        logger().info(module, message);
     }
 
-    pub fn warn(message: impl std::fmt::Display) {
-        logger().warn(message);
+    pub fn warn(module: &str, message: impl std::fmt::Display) {
+        logger().warn(module, message);
     }
 
-    pub fn error(message: impl std::fmt::Display) {
-        logger().error(message);
+    pub fn error(module: &str, message: impl std::fmt::Display) {
+        logger().error(module, message);
     }
 
-    pub fn error_with_context(context: &[String], message: impl std::fmt::Display) {
-        logger().error_with_context(context, message);
+    pub fn error_with_context(
+        module: &str,
+        context: &[String],
+        message: impl std::fmt::Display,
+    ) {
+        logger().error_with_context(module, context, message);
+    }
+
+    pub fn warn_with_location(
+        module: &str,
+        location: &Location,
+        message: impl std::fmt::Display,
+    ) {
+        logger().warn_with_location(module, location, message);
+    }
+
+    pub fn error_with_location(
+        module: &str,
+        location: &Location,
+        message: impl std::fmt::Display,
+    ) {
+        logger().error_with_location(module, location, message);
+    }
+
+    pub fn emit_diagnostic(diagnostic: &Diagnostic, source: &str) {
+        logger().emit_diagnostic(diagnostic, source);
     }
 
     #[must_use]
@@ -150,3 +959,264 @@ pub mod log {
         logger().is_human()
     }
 }
+
+#[cfg(test)]
+mod diagnostic_tests {
+    use super::*;
+
+    #[test]
+    fn to_json_includes_code_and_labels() {
+        let diagnostic = Diagnostic::new(Severity::Error, "boom")
+            .with_code("E0123")
+            .with_label("a.rs", 2..5, "here");
+        assert_eq!(
+            diagnostic.to_json(),
+            "{\"severity\":\"error\",\"message\":\"boom\",\"code\":\"E0123\",\"labels\":\
+             [{\"file\":\"a.rs\",\"start\":2,\"end\":5,\"label\":\"here\"}]}"
+        );
+    }
+
+    #[test]
+    fn to_json_omits_code_and_keeps_empty_labels() {
+        let diagnostic = Diagnostic::new(Severity::Warning, "boom");
+        assert_eq!(
+            diagnostic.to_json(),
+            "{\"severity\":\"warning\",\"message\":\"boom\",\"labels\":[]}"
+        );
+    }
+
+    #[test]
+    fn github_location_derives_line_and_col_from_first_label() {
+        let source = "fn main() {\n    let x = 1;\n}\n";
+        let diagnostic = Diagnostic::new(Severity::Error, "boom").with_label("a.rs", 16..17, "here");
+        let location = diagnostic_github_location(&diagnostic, source);
+        assert_eq!(location.file, Some("a.rs".to_string()));
+        assert_eq!(location.line, Some(2));
+        assert_eq!(location.col, Some(5));
+    }
+
+    #[test]
+    fn github_location_defaults_when_no_labels() {
+        let diagnostic = Diagnostic::new(Severity::Error, "boom");
+        assert_eq!(diagnostic_github_location(&diagnostic, ""), Location::default());
+    }
+}
+
+#[cfg(test)]
+mod location_tests {
+    use super::*;
+
+    #[test]
+    fn github_annotation_with_file_and_span() {
+        let location = Location::new().file("a.rs").line(3).end_line(3).col(5).end_col(9);
+        assert_eq!(
+            location.github_annotation("error", "boom"),
+            "::error file=a.rs,line=3,endLine=3,col=5,endColumn=9::boom"
+        );
+    }
+
+    #[test]
+    fn github_annotation_without_file() {
+        assert_eq!(Location::new().github_annotation("warning", "boom"), "::warning::boom");
+    }
+
+    #[test]
+    fn human_prefix_with_file_line_and_col() {
+        let location = Location::new().file("a.rs").line(3).col(5);
+        assert_eq!(location.human_prefix(), "a.rs:3:5");
+    }
+
+    #[test]
+    fn human_prefix_without_col() {
+        let location = Location::new().file("a.rs").line(3);
+        assert_eq!(location.human_prefix(), "a.rs:3");
+    }
+
+    #[test]
+    fn human_prefix_without_file() {
+        assert_eq!(Location::new().human_prefix(), "");
+    }
+
+    #[test]
+    fn to_json_includes_present_fields() {
+        let location = Location::new().file("a.rs").line(3).col(5);
+        assert_eq!(location.to_json(), "{\"file\":\"a.rs\",\"line\":3,\"col\":5}");
+    }
+
+    #[test]
+    fn to_json_empty_when_no_fields_set() {
+        assert_eq!(Location::new().to_json(), "{}");
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+
+    #[test]
+    fn composes_template_filter_and_color() {
+        let template = LogTemplate::parse("{level}: {message}").unwrap();
+        let filter = LevelDirectives::parse("warn").unwrap();
+        let logger = Logger::builder(LogFormat::Human)
+            .template(template.clone())
+            .filter(filter.clone())
+            .color(ColorChoice::Never)
+            .build();
+        assert_eq!(logger.template, Some(template));
+        assert_eq!(logger.filter, filter);
+        assert!(!logger.color);
+    }
+}
+
+#[cfg(test)]
+mod locate_tests {
+    use super::*;
+
+    #[test]
+    fn locates_line_and_column() {
+        let source = "fn main() {\n    let x = 1;\n}\n";
+        let (line, col, range) = locate(source, 16);
+        assert_eq!(line, 2);
+        assert_eq!(col, 5);
+        assert_eq!(&source[range], "    let x = 1;");
+    }
+
+    #[test]
+    fn clamps_past_end_of_source() {
+        let source = "abc";
+        let (line, col, _) = locate(source, 100);
+        assert_eq!((line, col), (1, 4));
+    }
+
+    #[test]
+    fn does_not_panic_on_non_char_boundary_offset() {
+        let source = "héllo world";
+        let mid_byte = source.char_indices().nth(1).unwrap().0 + 1;
+        assert!(!source.is_char_boundary(mid_byte));
+        let (line, _, _) = locate(source, mid_byte);
+        assert_eq!(line, 1);
+    }
+
+    #[test]
+    fn render_label_does_not_panic_on_non_char_boundary_range() {
+        let source = "héllo world";
+        let label = Label {
+            file: "f".to_string(),
+            byte_range: 2..4,
+            label: "bad".to_string(),
+        };
+        let rendered = render_label(source, &label, &|s| s.to_string());
+        assert!(rendered.contains("héllo world"));
+    }
+}
+
+#[cfg(test)]
+mod level_filter_tests {
+    use super::*;
+
+    #[test]
+    fn parses_global_level() {
+        let directives = LevelDirectives::parse("warn").unwrap();
+        assert_eq!(directives.level_for("anything"), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn parses_per_module_override() {
+        let directives = LevelDirectives::parse("warn,ingest::csv=debug").unwrap();
+        assert_eq!(directives.level_for("ingest::csv"), LevelFilter::Debug);
+        assert_eq!(directives.level_for("other"), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn default_passes_everything() {
+        assert_eq!(LevelDirectives::default().level_for("anything"), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn rejects_invalid_level() {
+        assert!(LevelDirectives::parse("bogus").is_err());
+        assert!(LevelDirectives::parse("mod=bogus").is_err());
+    }
+}
+
+#[cfg(test)]
+mod json_tests {
+    use super::*;
+
+    #[test]
+    fn escapes_control_characters() {
+        assert_eq!(json_escape("a\"b\\c\nd\te\u{1}"), "a\\\"b\\\\c\\nd\\te\\u0001");
+    }
+
+    #[test]
+    fn json_line_includes_context_array() {
+        let line = json_line("error", "boom", &["a".to_string(), "b".to_string()], None);
+        assert!(line.contains("\"context\":[\"a\",\"b\"]"));
+        assert!(line.starts_with("{\"level\":\"error\",\"message\":\"boom\""));
+    }
+
+    #[test]
+    fn json_line_omits_context_when_empty() {
+        let line = json_line("info", "hello", &[], None);
+        assert!(!line.contains("\"context\""));
+    }
+
+    #[test]
+    fn json_line_includes_location_when_given() {
+        let location = Location::new().file("a.rs").line(3);
+        let line = json_line("error", "boom", &[], Some(&location));
+        assert!(line.contains("\"location\":{\"file\":\"a.rs\",\"line\":3}"));
+    }
+
+    #[test]
+    fn from_str_accepts_json() {
+        assert_eq!("json".parse::<LogFormat>().unwrap(), LogFormat::Json);
+    }
+}
+
+#[cfg(test)]
+mod template_tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_placeholders() {
+        let template = LogTemplate::parse("[{timestamp}] {level}: {context} {message}").unwrap();
+        assert_eq!(
+            template.segments,
+            vec![
+                LogSegment::Literal("[".to_string()),
+                LogSegment::Timestamp,
+                LogSegment::Literal("] ".to_string()),
+                LogSegment::Level,
+                LogSegment::Literal(": ".to_string()),
+                LogSegment::Context { sep: " > ".to_string() },
+                LogSegment::Literal(" ".to_string()),
+                LogSegment::Message,
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_context_sep_modifier() {
+        let template = LogTemplate::parse("{context:sep= > }").unwrap();
+        assert_eq!(
+            template.segments,
+            vec![LogSegment::Context { sep: " > ".to_string() }]
+        );
+    }
+
+    #[test]
+    fn rejects_unterminated_placeholder() {
+        assert!(LogTemplate::parse("{level").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_placeholder() {
+        assert!(LogTemplate::parse("{bogus}").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_context_modifier() {
+        assert!(LogTemplate::parse("{context:bogus}").is_err());
+    }
+}